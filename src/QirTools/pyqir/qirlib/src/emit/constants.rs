@@ -0,0 +1,25 @@
+use inkwell::types::FloatType;
+use inkwell::values::IntValue;
+
+use super::types::Types;
+
+pub struct Constants<'ctx> {
+    pub zero: IntValue<'ctx>,
+    pub one: IntValue<'ctx>,
+    double_type: FloatType<'ctx>,
+}
+
+impl<'ctx> Constants<'ctx> {
+    pub fn new(types: &Types<'ctx>) -> Self {
+        Constants {
+            zero: types.int64.const_int(0, false),
+            one: types.int64.const_int(1, false),
+            double_type: types.double,
+        }
+    }
+
+    /// Lowers a rotation angle as a `double` constant.
+    pub fn double(&self, value: f64) -> inkwell::values::FloatValue<'ctx> {
+        self.double_type.const_float(value)
+    }
+}
@@ -0,0 +1,101 @@
+use inkwell::module::Module;
+use inkwell::values::FunctionValue;
+
+use super::types::Types;
+
+pub struct Intrinsics<'ctx> {
+    pub x: FunctionValue<'ctx>,
+    pub y: FunctionValue<'ctx>,
+    pub z: FunctionValue<'ctx>,
+    pub h: FunctionValue<'ctx>,
+    pub s: FunctionValue<'ctx>,
+    pub s_adj: FunctionValue<'ctx>,
+    pub t: FunctionValue<'ctx>,
+    pub t_adj: FunctionValue<'ctx>,
+    pub rx: FunctionValue<'ctx>,
+    pub ry: FunctionValue<'ctx>,
+    pub rz: FunctionValue<'ctx>,
+    pub cx: FunctionValue<'ctx>,
+    pub swap: FunctionValue<'ctx>,
+    pub m: FunctionValue<'ctx>,
+    pub mz: FunctionValue<'ctx>,
+    pub reset: FunctionValue<'ctx>,
+    // Controlled-functor ("__ctl") specializations: each takes the control
+    // qubits as an `Array*` followed by the target qubit.
+    pub x_ctl: FunctionValue<'ctx>,
+    pub h_ctl: FunctionValue<'ctx>,
+    pub y_ctl: FunctionValue<'ctx>,
+    pub z_ctl: FunctionValue<'ctx>,
+    pub s_ctl: FunctionValue<'ctx>,
+    pub t_ctl: FunctionValue<'ctx>,
+    // Controlled rotations take the control qubits as an `Array*`, then the
+    // angle, then the target qubit.
+    pub rx_ctl: FunctionValue<'ctx>,
+    pub ry_ctl: FunctionValue<'ctx>,
+    pub rz_ctl: FunctionValue<'ctx>,
+}
+
+impl<'ctx> Intrinsics<'ctx> {
+    pub fn new(module: &Module<'ctx>) -> Self {
+        let context = module.get_context();
+        let types = Types::new(&context, module);
+        let void = context.void_type();
+
+        let single_ty = void.fn_type(&[types.qubit_ptr().into()], false);
+        let controlled_ty =
+            void.fn_type(&[types.qubit_ptr().into(), types.qubit_ptr().into()], false);
+        let rotation_ty = void.fn_type(
+            &[types.double.into(), types.qubit_ptr().into()],
+            false,
+        );
+        let ctl_ty = void.fn_type(
+            &[types.array_ptr().into(), types.qubit_ptr().into()],
+            false,
+        );
+        let ctl_rotation_ty = void.fn_type(
+            &[
+                types.array_ptr().into(),
+                types.double.into(),
+                types.qubit_ptr().into(),
+            ],
+            false,
+        );
+        let m_ty = types
+            .result_ptr()
+            .fn_type(&[types.qubit_ptr().into()], false);
+        // Base Profile measurement: writes the outcome into a statically-addressed
+        // %Result* instead of returning a dynamically-allocated one.
+        let mz_ty = void.fn_type(
+            &[types.qubit_ptr().into(), types.result_ptr().into()],
+            false,
+        );
+
+        Intrinsics {
+            x: module.add_function("__quantum__qis__x__body", single_ty, None),
+            y: module.add_function("__quantum__qis__y__body", single_ty, None),
+            z: module.add_function("__quantum__qis__z__body", single_ty, None),
+            h: module.add_function("__quantum__qis__h__body", single_ty, None),
+            s: module.add_function("__quantum__qis__s__body", single_ty, None),
+            s_adj: module.add_function("__quantum__qis__s__adj", single_ty, None),
+            t: module.add_function("__quantum__qis__t__body", single_ty, None),
+            t_adj: module.add_function("__quantum__qis__t__adj", single_ty, None),
+            rx: module.add_function("__quantum__qis__rx__body", rotation_ty, None),
+            ry: module.add_function("__quantum__qis__ry__body", rotation_ty, None),
+            rz: module.add_function("__quantum__qis__rz__body", rotation_ty, None),
+            cx: module.add_function("__quantum__qis__cnot__body", controlled_ty, None),
+            swap: module.add_function("__quantum__qis__swap__body", controlled_ty, None),
+            m: module.add_function("__quantum__qis__m__body", m_ty, None),
+            mz: module.add_function("__quantum__qis__mz__body", mz_ty, None),
+            reset: module.add_function("__quantum__qis__reset__body", single_ty, None),
+            x_ctl: module.add_function("__quantum__qis__x__ctl", ctl_ty, None),
+            h_ctl: module.add_function("__quantum__qis__h__ctl", ctl_ty, None),
+            y_ctl: module.add_function("__quantum__qis__y__ctl", ctl_ty, None),
+            z_ctl: module.add_function("__quantum__qis__z__ctl", ctl_ty, None),
+            s_ctl: module.add_function("__quantum__qis__s__ctl", ctl_ty, None),
+            t_ctl: module.add_function("__quantum__qis__t__ctl", ctl_ty, None),
+            rx_ctl: module.add_function("__quantum__qis__rx__ctl", ctl_rotation_ty, None),
+            ry_ctl: module.add_function("__quantum__qis__ry__ctl", ctl_rotation_ty, None),
+            rz_ctl: module.add_function("__quantum__qis__rz__ctl", ctl_rotation_ty, None),
+        }
+    }
+}
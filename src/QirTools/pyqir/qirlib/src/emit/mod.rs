@@ -5,7 +5,7 @@ use self::intrinsics::Intrinsics;
 use self::runtime_library::RuntimeLibrary;
 use self::types::Types;
 
-use super::interop::SemanticModel;
+use super::interop::{Instruction, SemanticModel};
 use std::collections::BTreeMap;
 use std::path::Path;
 
@@ -15,11 +15,26 @@ mod qir;
 mod runtime_library;
 pub mod types;
 
+/// Controls how `Emitter::write` addresses qubits and results and how it
+/// surfaces measurement outcomes.
+///
+/// `Full` emits dynamic allocation/release calls and returns a
+/// runtime-allocated results array holding one sub-array per classical
+/// register; measurement outcomes are not stored into it. `BaseProfile`
+/// targets restricted hardware backends: every qubit and result is addressed by
+/// a compile-time constant id and measurements are surfaced through
+/// `__quantum__rt__result_record_output` calls instead of a results array.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Profile {
+    Full,
+    BaseProfile,
+}
+
 pub struct Emitter {}
 impl Emitter {
-    pub fn write(model: &SemanticModel, file_name: &str) {
+    pub fn write(model: &SemanticModel, file_name: &str, profile: Profile) {
         let ctx = inkwell::context::Context::create();
-        let context = Context::new(&ctx, model.name.as_str());
+        let context = Context::new(&ctx, model.name.as_str(), profile);
 
         let entrypoint = qir::get_entry_function(&context);
         let entry = context.context.append_basic_block(entrypoint, "entry");
@@ -29,11 +44,25 @@ impl Emitter {
 
         let registers = Emitter::write_registers(&model, &context);
 
-        let _ = Emitter::write_instructions(&model, &context, &qubits);
+        let recorded_results = Emitter::write_instructions(&model, &context, &qubits);
 
-        Emitter::free_qubits(&context, &qubits);
-        let output = registers.get("results").unwrap();
-        context.builder.build_return(Some(output));
+        match context.profile {
+            Profile::Full => {
+                Emitter::free_qubits(&context, &qubits);
+                let output = registers.get("results").unwrap();
+                context.builder.build_return(Some(output));
+            }
+            Profile::BaseProfile => {
+                Emitter::record_outputs(&context, &recorded_results);
+                qir::configure_base_profile_entry_point(
+                    &context,
+                    entrypoint,
+                    qubits.len() as u64,
+                    recorded_results.len() as u64,
+                );
+                context.builder.build_return(None);
+            }
+        }
 
         context.emit_ir(file_name);
     }
@@ -49,9 +78,12 @@ impl Emitter {
         context: &Context<'ctx>,
     ) -> BTreeMap<String, BasicValueEnum<'ctx>> {
         let mut qubits = BTreeMap::new();
-        for reg in model.qubits.iter() {
+        for (id, reg) in model.qubits.iter().enumerate() {
             let indexed_name = format!("{}{}", &reg.name[..], reg.index);
-            let value = qir::qubits::emit_allocate(&context, indexed_name.as_str());
+            let value = match context.profile {
+                Profile::Full => qir::qubits::emit_allocate(&context, indexed_name.as_str()),
+                Profile::BaseProfile => qir::qubits::emit_static(&context, id as u64),
+            };
             qubits.insert(indexed_name, value);
         }
         qubits
@@ -61,6 +93,12 @@ impl Emitter {
         model: &SemanticModel,
         context: &Context<'ctx>,
     ) -> BTreeMap<String, BasicValueEnum<'ctx>> {
+        // Base Profile has no results array: every measurement is recorded
+        // directly through an output-recording call instead.
+        if context.profile == Profile::BaseProfile {
+            return BTreeMap::new();
+        }
+
         let mut registers = BTreeMap::new();
         let number_of_registers = model.registers.len() as u64;
         if number_of_registers > 0 {
@@ -83,13 +121,40 @@ impl Emitter {
         }
     }
 
+    /// Emits every instruction in `model`. In Base Profile mode, measurements are
+    /// not recorded in place; instead their (result id, classical target) pairs
+    /// are returned so `write` can emit the output-recording calls once, at the
+    /// end of the entry block.
     fn write_instructions<'ctx>(
         model: &SemanticModel,
         context: &Context<'ctx>,
         qubits: &BTreeMap<String, BasicValueEnum<'ctx>>,
-    ) {
+    ) -> Vec<(u64, String)> {
+        let mut recorded_results = vec![];
+        let mut next_result_id = 0u64;
         for inst in model.instructions.iter() {
-            qir::instructions::emit(context, inst, qubits);
+            match (context.profile, inst) {
+                (Profile::BaseProfile, Instruction::M { qubit, target }) => {
+                    let qubit_value = qubits.get(qubit).unwrap();
+                    let result_value = qir::results::emit_static(context, next_result_id);
+                    context.builder.build_call(
+                        context.intrinsics.mz,
+                        &[(*qubit_value).into(), result_value.into()],
+                        "",
+                    );
+                    recorded_results.push((next_result_id, target.clone()));
+                    next_result_id += 1;
+                }
+                _ => qir::instructions::emit(context, inst, qubits),
+            }
+        }
+        recorded_results
+    }
+
+    fn record_outputs<'ctx>(context: &Context<'ctx>, recorded_results: &[(u64, String)]) {
+        for (id, target) in recorded_results.iter() {
+            let result_value = qir::results::emit_static(context, *id);
+            qir::results::emit_record_output(context, &result_value, target);
         }
     }
 }
@@ -102,9 +167,10 @@ pub struct Context<'ctx> {
     pub(crate) runtime_library: RuntimeLibrary<'ctx>,
     pub(crate) intrinsics: Intrinsics<'ctx>,
     pub(crate) constants: Constants<'ctx>,
+    pub(crate) profile: Profile,
 }
 impl<'ctx> Context<'ctx> {
-    pub fn new(context: &'ctx inkwell::context::Context, name: &'ctx str) -> Self {
+    pub fn new(context: &'ctx inkwell::context::Context, name: &'ctx str, profile: Profile) -> Self {
         let builder = context.create_builder();
 
         let module = qir::load_module_from_bitcode_file(&context, name);
@@ -112,7 +178,7 @@ impl<'ctx> Context<'ctx> {
         let types = Types::new(&context, &module);
         let runtime_library = RuntimeLibrary::new(&module);
         let intrinsics = Intrinsics::new(&module);
-        let constants = Constants::new(&module, &types);
+        let constants = Constants::new(&types);
         Context {
             builder: builder,
             module: module,
@@ -121,6 +187,7 @@ impl<'ctx> Context<'ctx> {
             runtime_library: runtime_library,
             intrinsics: intrinsics,
             constants: constants,
+            profile: profile,
         }
     }
 
@@ -148,7 +215,9 @@ impl<'ctx> Context<'ctx> {
 
 #[cfg(test)]
 mod tests {
-    use crate::interop::{ClassicalRegister, Controlled, Instruction, QuantumRegister, Single};
+    use crate::interop::{
+        ClassicalRegister, Controlled, Instruction, QuantumRegister, Rotated, Single,
+    };
 
     use super::*;
     #[test]
@@ -210,7 +279,7 @@ mod tests {
         model.add_inst(Instruction::Reset(Single::new(String::from("input_2"))));
         model.add_inst(Instruction::Reset(Single::new(String::from("input_3"))));
         model.add_inst(Instruction::Reset(Single::new(String::from("input_4"))));
-        Emitter::write(&model, "BernsteinVazirani.ll");
+        Emitter::write(&model, "BernsteinVazirani.ll", Profile::Full);
     }
     #[test]
     fn bell_measure() {
@@ -230,7 +299,7 @@ mod tests {
             qubit: String::from("qr1"),
             target: String::from("qc1"),
         });
-        Emitter::write(&model, "bell_measure.ll");
+        Emitter::write(&model, "bell_measure.ll", Profile::Full);
     }
 
     #[test]
@@ -243,6 +312,58 @@ mod tests {
 
         model.add_inst(Instruction::H(Single::new(String::from("qr0"))));
         model.add_inst(Instruction::Cx(Controlled::new(String::from("qr0"), String::from("qr1"))));
-        Emitter::write(&model, "bell_no_measure.ll");
+        Emitter::write(&model, "bell_no_measure.ll", Profile::Full);
+    }
+
+    #[test]
+    fn bell_measure_base_profile() {
+        let name = String::from("Bell circuit");
+        let mut model = SemanticModel::new(name);
+        model.add_reg(QuantumRegister::new(String::from("qr"), 0).as_register());
+        model.add_reg(QuantumRegister::new(String::from("qr"), 1).as_register());
+        model.add_reg(ClassicalRegister::new(String::from("qc"), 2).as_register());
+
+        model.add_inst(Instruction::H(Single::new(String::from("qr0"))));
+        model.add_inst(Instruction::Cx(Controlled::new(String::from("qr0"), String::from("qr1"))));
+        model.add_inst(Instruction::M {
+            qubit: String::from("qr0"),
+            target: String::from("qc0"),
+        });
+        model.add_inst(Instruction::M {
+            qubit: String::from("qr1"),
+            target: String::from("qc1"),
+        });
+        Emitter::write(&model, "bell_measure_base_profile.ll", Profile::BaseProfile);
+    }
+
+    #[test]
+    fn rotations_and_functors() {
+        let name = String::from("Rotations and functors circuit");
+        let mut model = SemanticModel::new(name);
+        model.add_reg(QuantumRegister::new(String::from("qr"), 0).as_register());
+        model.add_reg(QuantumRegister::new(String::from("qr"), 1).as_register());
+        model.add_reg(QuantumRegister::new(String::from("qr"), 2).as_register());
+        model.add_reg(ClassicalRegister::new(String::from("qc"), 1).as_register());
+
+        model.add_inst(Instruction::Rx(Rotated::new(
+            String::from("qr0"),
+            std::f64::consts::FRAC_PI_2,
+        )));
+        model.add_inst(Instruction::Adjoint(Box::new(Instruction::S(Single::new(
+            String::from("qr0"),
+        )))));
+        model.add_inst(Instruction::Swap(Controlled::new(
+            String::from("qr0"),
+            String::from("qr1"),
+        )));
+        model.add_inst(Instruction::Controlled(
+            vec![String::from("qr0"), String::from("qr1")],
+            Box::new(Instruction::X(Single::new(String::from("qr2")))),
+        ));
+        model.add_inst(Instruction::M {
+            qubit: String::from("qr2"),
+            target: String::from("qc0"),
+        });
+        Emitter::write(&model, "rotations_and_functors.ll", Profile::Full);
     }
 }
\ No newline at end of file
@@ -0,0 +1,74 @@
+use inkwell::values::BasicValueEnum;
+
+use crate::emit::Context;
+
+pub fn emit_array_allocate1d<'ctx>(
+    context: &Context<'ctx>,
+    element_size_bytes: u64,
+    number_of_elements: u64,
+    name: &str,
+) -> BasicValueEnum<'ctx> {
+    let size = context.types.int64.const_int(element_size_bytes, false);
+    let count = context.types.int64.const_int(number_of_elements, false);
+    context
+        .builder
+        .build_call(
+            context.runtime_library.array1d_create,
+            &[size.into(), count.into()],
+            name,
+        )
+        .try_as_basic_value()
+        .left()
+        .unwrap()
+}
+
+pub fn emit_empty_result_array_allocate1d<'ctx>(
+    context: &Context<'ctx>,
+    name: &str,
+) -> BasicValueEnum<'ctx> {
+    emit_array_allocate1d(context, 8, 0, name)
+}
+
+pub fn emit_array_1d<'ctx>(
+    context: &Context<'ctx>,
+    name: &str,
+    size: u64,
+) -> BasicValueEnum<'ctx> {
+    emit_array_allocate1d(context, 8, size, name)
+}
+
+/// Builds an `Array*` of qubit pointers, e.g. the control-qubit list passed to
+/// a controlled-functor ("__ctl") intrinsic.
+pub fn emit_qubit_array<'ctx>(
+    context: &Context<'ctx>,
+    qubits: &[BasicValueEnum<'ctx>],
+    name: &str,
+) -> BasicValueEnum<'ctx> {
+    let array = emit_array_allocate1d(context, 8, qubits.len() as u64, name);
+    set_elements(context, &array, qubits.to_vec(), name);
+    array
+}
+
+pub fn set_elements<'ctx>(
+    context: &Context<'ctx>,
+    array: &BasicValueEnum<'ctx>,
+    elements: Vec<BasicValueEnum<'ctx>>,
+    name: &str,
+) {
+    for (index, element) in elements.iter().enumerate() {
+        let index_value = context.types.int64.const_int(index as u64, false);
+        let slot = context
+            .builder
+            .build_call(
+                context.runtime_library.array_set_element_ptr1d,
+                &[(*array).into(), index_value.into()],
+                &format!("{}_{}", name, index),
+            )
+            .try_as_basic_value()
+            .left()
+            .unwrap();
+        context
+            .builder
+            .build_store(slot.into_pointer_value(), *element);
+    }
+}
@@ -0,0 +1,194 @@
+use std::collections::BTreeMap;
+
+use inkwell::values::{BasicValueEnum, FunctionValue};
+
+use crate::emit::Context;
+use crate::interop::{Instruction, Rotated};
+
+use super::array1d;
+
+pub fn emit<'ctx>(
+    context: &Context<'ctx>,
+    inst: &Instruction,
+    qubits: &BTreeMap<String, BasicValueEnum<'ctx>>,
+) {
+    match inst {
+        Instruction::X(single) => emit_single(context, context.intrinsics.x, qubits, &single.qubit),
+        Instruction::Y(single) => emit_single(context, context.intrinsics.y, qubits, &single.qubit),
+        Instruction::Z(single) => emit_single(context, context.intrinsics.z, qubits, &single.qubit),
+        Instruction::H(single) => emit_single(context, context.intrinsics.h, qubits, &single.qubit),
+        Instruction::S(single) => emit_single(context, context.intrinsics.s, qubits, &single.qubit),
+        Instruction::Sdg(single) => {
+            emit_single(context, context.intrinsics.s_adj, qubits, &single.qubit)
+        }
+        Instruction::T(single) => emit_single(context, context.intrinsics.t, qubits, &single.qubit),
+        Instruction::Tdg(single) => {
+            emit_single(context, context.intrinsics.t_adj, qubits, &single.qubit)
+        }
+        Instruction::Rx(rotated) => emit_rotation(context, context.intrinsics.rx, qubits, rotated),
+        Instruction::Ry(rotated) => emit_rotation(context, context.intrinsics.ry, qubits, rotated),
+        Instruction::Rz(rotated) => emit_rotation(context, context.intrinsics.rz, qubits, rotated),
+        Instruction::Cx(controlled) => emit_controlled(
+            context,
+            context.intrinsics.cx,
+            qubits,
+            &controlled.control,
+            &controlled.target,
+        ),
+        Instruction::Swap(pair) => emit_controlled(
+            context,
+            context.intrinsics.swap,
+            qubits,
+            &pair.control,
+            &pair.target,
+        ),
+        Instruction::M { qubit, target: _ } => {
+            let qubit_value = qubits.get(qubit).unwrap();
+            context.builder.build_call(
+                context.intrinsics.m,
+                &[(*qubit_value).into()],
+                qubit.as_str(),
+            );
+        }
+        Instruction::Reset(single) => {
+            emit_single(context, context.intrinsics.reset, qubits, &single.qubit)
+        }
+        Instruction::Adjoint(inner) => emit_adjoint(context, inner, qubits),
+        Instruction::Controlled(controls, inner) => {
+            emit_multi_controlled(context, controls, inner, qubits)
+        }
+    }
+}
+
+fn emit_single<'ctx>(
+    context: &Context<'ctx>,
+    intrinsic: FunctionValue<'ctx>,
+    qubits: &BTreeMap<String, BasicValueEnum<'ctx>>,
+    qubit: &str,
+) {
+    let qubit_value = qubits.get(qubit).unwrap();
+    context
+        .builder
+        .build_call(intrinsic, &[(*qubit_value).into()], "");
+}
+
+fn emit_controlled<'ctx>(
+    context: &Context<'ctx>,
+    intrinsic: FunctionValue<'ctx>,
+    qubits: &BTreeMap<String, BasicValueEnum<'ctx>>,
+    control: &str,
+    target: &str,
+) {
+    let control_value = qubits.get(control).unwrap();
+    let target_value = qubits.get(target).unwrap();
+    context.builder.build_call(
+        intrinsic,
+        &[(*control_value).into(), (*target_value).into()],
+        "",
+    );
+}
+
+fn emit_rotation<'ctx>(
+    context: &Context<'ctx>,
+    intrinsic: FunctionValue<'ctx>,
+    qubits: &BTreeMap<String, BasicValueEnum<'ctx>>,
+    rotated: &Rotated,
+) {
+    let angle = context.constants.double(rotated.theta);
+    let qubit_value = qubits.get(&rotated.qubit).unwrap();
+    context
+        .builder
+        .build_call(intrinsic, &[angle.into(), (*qubit_value).into()], "");
+}
+
+/// Applies the adjoint specialization of `inner` by resolving it to a plain
+/// instruction via `Instruction::adjoint` and re-dispatching through `emit`.
+fn emit_adjoint<'ctx>(
+    context: &Context<'ctx>,
+    inner: &Instruction,
+    qubits: &BTreeMap<String, BasicValueEnum<'ctx>>,
+) {
+    emit(context, &inner.adjoint(), qubits)
+}
+
+/// Applies `inner` controlled on every qubit in `controls`, dispatching to the
+/// matching `__ctl` intrinsic. `Adjoint`/nested `Controlled` wrappers are
+/// resolved first so e.g. `Controlled(ctrls, Adjoint(Rx(theta)))` lowers to a
+/// controlled `Rx(-theta)` rather than panicking.
+fn emit_multi_controlled<'ctx>(
+    context: &Context<'ctx>,
+    controls: &[String],
+    inner: &Instruction,
+    qubits: &BTreeMap<String, BasicValueEnum<'ctx>>,
+) {
+    match inner {
+        Instruction::Adjoint(doubly_inner) => {
+            emit_multi_controlled(context, controls, &doubly_inner.adjoint(), qubits);
+            return;
+        }
+        Instruction::Controlled(inner_controls, doubly_inner) => {
+            let mut combined_controls = controls.to_vec();
+            combined_controls.extend(inner_controls.iter().cloned());
+            emit_multi_controlled(context, &combined_controls, doubly_inner, qubits);
+            return;
+        }
+        _ => {}
+    }
+
+    match inner {
+        Instruction::Rx(rotated) => {
+            emit_ctl_rotation(context, context.intrinsics.rx_ctl, controls, qubits, rotated);
+            return;
+        }
+        Instruction::Ry(rotated) => {
+            emit_ctl_rotation(context, context.intrinsics.ry_ctl, controls, qubits, rotated);
+            return;
+        }
+        Instruction::Rz(rotated) => {
+            emit_ctl_rotation(context, context.intrinsics.rz_ctl, controls, qubits, rotated);
+            return;
+        }
+        _ => {}
+    }
+
+    let (intrinsic, target) = match inner {
+        Instruction::X(single) => (context.intrinsics.x_ctl, &single.qubit),
+        Instruction::Y(single) => (context.intrinsics.y_ctl, &single.qubit),
+        Instruction::Z(single) => (context.intrinsics.z_ctl, &single.qubit),
+        Instruction::H(single) => (context.intrinsics.h_ctl, &single.qubit),
+        Instruction::S(single) => (context.intrinsics.s_ctl, &single.qubit),
+        Instruction::T(single) => (context.intrinsics.t_ctl, &single.qubit),
+        other => panic!(
+            "the controlled functor is not supported for this instruction: {}",
+            other.name()
+        ),
+    };
+
+    let control_values: Vec<_> = controls.iter().map(|c| *qubits.get(c).unwrap()).collect();
+    let control_array = array1d::emit_qubit_array(context, &control_values, "ctls");
+    let target_value = qubits.get(target).unwrap();
+    context.builder.build_call(
+        intrinsic,
+        &[control_array.into(), (*target_value).into()],
+        "",
+    );
+}
+
+fn emit_ctl_rotation<'ctx>(
+    context: &Context<'ctx>,
+    intrinsic: FunctionValue<'ctx>,
+    controls: &[String],
+    qubits: &BTreeMap<String, BasicValueEnum<'ctx>>,
+    rotated: &Rotated,
+) {
+    let control_values: Vec<_> = controls.iter().map(|c| *qubits.get(c).unwrap()).collect();
+    let control_array = array1d::emit_qubit_array(context, &control_values, "ctls");
+    let angle = context.constants.double(rotated.theta);
+    let target_value = qubits.get(&rotated.qubit).unwrap();
+    context.builder.build_call(
+        intrinsic,
+        &[control_array.into(), angle.into(), (*target_value).into()],
+        "",
+    );
+}
+
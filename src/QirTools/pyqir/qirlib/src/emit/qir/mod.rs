@@ -0,0 +1,61 @@
+use inkwell::attributes::AttributeLoc;
+use inkwell::module::{FlagBehavior, Module};
+use inkwell::values::FunctionValue;
+
+use super::{Context, Profile};
+
+pub mod array1d;
+pub mod instructions;
+pub mod qubits;
+pub mod results;
+
+pub fn load_module_from_bitcode_file<'ctx>(
+    context: &'ctx inkwell::context::Context,
+    name: &str,
+) -> Module<'ctx> {
+    context.create_module(name)
+}
+
+pub fn get_entry_function<'ctx>(context: &Context<'ctx>) -> FunctionValue<'ctx> {
+    let fn_type = match context.profile {
+        // Base Profile entry points have no return value: every result is recorded
+        // explicitly via `__quantum__rt__result_record_output`.
+        Profile::BaseProfile => context.types.void.fn_type(&[], false),
+        Profile::Full => context.types.array_ptr().fn_type(&[], false),
+    };
+    let fn_value = context.module.add_function("main", fn_type, None);
+    fn_value.set_linkage(inkwell::module::Linkage::External);
+    fn_value
+}
+
+/// Marks `entrypoint` with the attributes and module flags a Base Profile
+/// consumer expects: the `EntryPoint` attribute, the required qubit/result
+/// counts, and a `dynamic_result_management` flag of `false` declaring that no
+/// result is ever allocated or released at runtime.
+pub fn configure_base_profile_entry_point<'ctx>(
+    context: &Context<'ctx>,
+    entrypoint: FunctionValue<'ctx>,
+    required_qubits: u64,
+    required_results: u64,
+) {
+    let llvm_context = context.context;
+    entrypoint.add_attribute(
+        AttributeLoc::Function,
+        llvm_context.create_string_attribute("EntryPoint", ""),
+    );
+    entrypoint.add_attribute(
+        AttributeLoc::Function,
+        llvm_context.create_string_attribute("requiredQubits", &required_qubits.to_string()),
+    );
+    entrypoint.add_attribute(
+        AttributeLoc::Function,
+        llvm_context.create_string_attribute("requiredResults", &required_results.to_string()),
+    );
+
+    let dynamic_result_management = context.types.bool.const_int(0, false);
+    context.module.add_basic_value_flag(
+        "dynamic_result_management",
+        FlagBehavior::Error,
+        dynamic_result_management,
+    );
+}
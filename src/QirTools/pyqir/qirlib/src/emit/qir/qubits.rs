@@ -0,0 +1,30 @@
+use inkwell::values::BasicValueEnum;
+
+use crate::emit::Context;
+
+pub fn emit_allocate<'ctx>(context: &Context<'ctx>, name: &str) -> BasicValueEnum<'ctx> {
+    let value = context
+        .builder
+        .build_call(context.runtime_library.qubit_allocate, &[], name)
+        .try_as_basic_value()
+        .left()
+        .unwrap();
+    value
+}
+
+pub fn emit_release<'ctx>(context: &Context<'ctx>, value: &BasicValueEnum<'ctx>) {
+    context
+        .builder
+        .build_call(context.runtime_library.qubit_release, &[(*value).into()], "");
+}
+
+/// Builds the constant `%Qubit* inttoptr (i64 id to %Qubit*)` used to address a
+/// statically-numbered qubit in Base Profile mode, instead of calling
+/// `__quantum__rt__qubit_allocate`.
+pub fn emit_static<'ctx>(context: &Context<'ctx>, id: u64) -> BasicValueEnum<'ctx> {
+    let addr = context.types.int64.const_int(id, false);
+    context
+        .builder
+        .build_int_to_ptr(addr, context.types.qubit_ptr(), "qubit")
+        .into()
+}
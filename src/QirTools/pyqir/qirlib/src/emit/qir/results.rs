@@ -0,0 +1,29 @@
+use inkwell::values::BasicValueEnum;
+
+use crate::emit::Context;
+
+/// Builds the constant `%Result* inttoptr (i64 id to %Result*)` used to address a
+/// statically-numbered result in Base Profile mode.
+pub fn emit_static<'ctx>(context: &Context<'ctx>, id: u64) -> BasicValueEnum<'ctx> {
+    let addr = context.types.int64.const_int(id, false);
+    context
+        .builder
+        .build_int_to_ptr(addr, context.types.result_ptr(), "result")
+        .into()
+}
+
+pub fn emit_record_output<'ctx>(
+    context: &Context<'ctx>,
+    result: &BasicValueEnum<'ctx>,
+    tag: &str,
+) {
+    let tag_ptr = context
+        .builder
+        .build_global_string_ptr(tag, "tag")
+        .as_pointer_value();
+    context.builder.build_call(
+        context.runtime_library.result_record_output,
+        &[(*result).into(), tag_ptr.into()],
+        "",
+    );
+}
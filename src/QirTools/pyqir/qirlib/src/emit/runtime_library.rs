@@ -0,0 +1,79 @@
+use inkwell::module::Module;
+use inkwell::values::FunctionValue;
+use inkwell::AddressSpace;
+
+use super::types::Types;
+
+pub struct RuntimeLibrary<'ctx> {
+    pub qubit_allocate: FunctionValue<'ctx>,
+    pub qubit_release: FunctionValue<'ctx>,
+    pub result_get_one: FunctionValue<'ctx>,
+    pub result_equal: FunctionValue<'ctx>,
+    pub result_record_output: FunctionValue<'ctx>,
+    pub array1d_create: FunctionValue<'ctx>,
+    pub array_set_element_ptr1d: FunctionValue<'ctx>,
+}
+
+impl<'ctx> RuntimeLibrary<'ctx> {
+    pub fn new(module: &Module<'ctx>) -> Self {
+        let context = module.get_context();
+        let types = Types::new(&context, module);
+        let i8ptr = context.i8_type().ptr_type(AddressSpace::Generic);
+
+        let qubit_allocate_ty = types.qubit_ptr().fn_type(&[], false);
+        let qubit_allocate =
+            module.add_function("__quantum__rt__qubit_allocate", qubit_allocate_ty, None);
+
+        let qubit_release_ty = context
+            .void_type()
+            .fn_type(&[types.qubit_ptr().into()], false);
+        let qubit_release =
+            module.add_function("__quantum__rt__qubit_release", qubit_release_ty, None);
+
+        let result_get_one_ty = types.result_ptr().fn_type(&[], false);
+        let result_get_one =
+            module.add_function("__quantum__rt__result_get_one", result_get_one_ty, None);
+
+        let result_equal_ty = context.bool_type().fn_type(
+            &[types.result_ptr().into(), types.result_ptr().into()],
+            false,
+        );
+        let result_equal =
+            module.add_function("__quantum__rt__result_equal", result_equal_ty, None);
+
+        let result_record_output_ty = context
+            .void_type()
+            .fn_type(&[types.result_ptr().into(), i8ptr.into()], false);
+        let result_record_output = module.add_function(
+            "__quantum__rt__result_record_output",
+            result_record_output_ty,
+            None,
+        );
+
+        let array1d_create_ty = types
+            .array_ptr()
+            .fn_type(&[types.int64.into(), types.int64.into()], false);
+        let array1d_create =
+            module.add_function("__quantum__rt__array_create_1d", array1d_create_ty, None);
+
+        let array_set_element_ptr1d_ty = i8ptr.fn_type(
+            &[types.array_ptr().into(), types.int64.into()],
+            false,
+        );
+        let array_set_element_ptr1d = module.add_function(
+            "__quantum__rt__array_get_element_ptr_1d",
+            array_set_element_ptr1d_ty,
+            None,
+        );
+
+        RuntimeLibrary {
+            qubit_allocate,
+            qubit_release,
+            result_get_one,
+            result_equal,
+            result_record_output,
+            array1d_create,
+            array_set_element_ptr1d,
+        }
+    }
+}
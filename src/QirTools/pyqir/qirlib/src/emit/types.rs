@@ -0,0 +1,49 @@
+use inkwell::context::Context;
+use inkwell::module::Module;
+use inkwell::types::{FloatType, IntType, PointerType, StructType, VoidType};
+
+pub struct Types<'ctx> {
+    pub qubit: StructType<'ctx>,
+    pub result: StructType<'ctx>,
+    pub array: StructType<'ctx>,
+    pub void: VoidType<'ctx>,
+    pub int64: IntType<'ctx>,
+    pub bool: IntType<'ctx>,
+    pub double: FloatType<'ctx>,
+}
+
+impl<'ctx> Types<'ctx> {
+    pub fn new(context: &'ctx Context, module: &Module<'ctx>) -> Self {
+        Types {
+            qubit: Types::get_or_create_opaque(context, module, "Qubit"),
+            result: Types::get_or_create_opaque(context, module, "Result"),
+            array: Types::get_or_create_opaque(context, module, "Array"),
+            void: context.void_type(),
+            int64: context.i64_type(),
+            bool: context.bool_type(),
+            double: context.f64_type(),
+        }
+    }
+
+    fn get_or_create_opaque(
+        context: &'ctx Context,
+        module: &Module<'ctx>,
+        name: &str,
+    ) -> StructType<'ctx> {
+        module
+            .get_struct_type(name)
+            .unwrap_or_else(|| context.opaque_struct_type(name))
+    }
+
+    pub fn qubit_ptr(&self) -> PointerType<'ctx> {
+        self.qubit.ptr_type(inkwell::AddressSpace::Generic)
+    }
+
+    pub fn result_ptr(&self) -> PointerType<'ctx> {
+        self.result.ptr_type(inkwell::AddressSpace::Generic)
+    }
+
+    pub fn array_ptr(&self) -> PointerType<'ctx> {
+        self.array.ptr_type(inkwell::AddressSpace::Generic)
+    }
+}
@@ -0,0 +1,234 @@
+use crate::interop::{ClassicalRegister, Controlled, Instruction, QuantumRegister, Rotated, SemanticModel, Single};
+
+/// A byte-offset range into the source text a `ParseError` was raised from.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Span {
+    pub start: usize,
+    pub end: usize,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub message: String,
+    pub span: Span,
+}
+
+impl ParseError {
+    fn new(message: impl Into<String>, span: Span) -> Self {
+        ParseError {
+            message: message.into(),
+            span,
+        }
+    }
+}
+
+/// Parses a subset of OpenQASM 2.0 — `qreg`/`creg` declarations, `h`, `x`,
+/// `cx`, `measure q -> c`, `reset`, and `rx`/`ry`/`rz(theta)` — into a
+/// `SemanticModel` ready for `Emitter::write`.
+pub fn parse(src: &str) -> Result<SemanticModel, ParseError> {
+    let mut model = SemanticModel::new(String::from("openqasm"));
+
+    let mut offset = 0usize;
+    for raw_statement in src.split(';') {
+        let span = Span {
+            start: offset,
+            end: offset + raw_statement.len(),
+        };
+        offset += raw_statement.len() + 1;
+
+        let statement = strip_comment(raw_statement).trim();
+        if statement.is_empty() {
+            continue;
+        }
+        if statement.starts_with("OPENQASM") || statement.starts_with("include") {
+            continue;
+        }
+
+        parse_statement(&mut model, statement, span)?;
+    }
+
+    Ok(model)
+}
+
+fn strip_comment(statement: &str) -> &str {
+    match statement.find("//") {
+        Some(index) => &statement[..index],
+        None => statement,
+    }
+}
+
+fn parse_statement(model: &mut SemanticModel, statement: &str, span: Span) -> Result<(), ParseError> {
+    if let Some(rest) = statement.strip_prefix("qreg ") {
+        let (name, size) = parse_declaration(rest, span)?;
+        for index in 0..size {
+            model.add_reg(QuantumRegister::new(name.clone(), index).as_register());
+        }
+        return Ok(());
+    }
+
+    if let Some(rest) = statement.strip_prefix("creg ") {
+        let (name, size) = parse_declaration(rest, span)?;
+        model.add_reg(ClassicalRegister::new(name, size).as_register());
+        return Ok(());
+    }
+
+    if let Some(rest) = statement.strip_prefix("measure ") {
+        let mut parts = rest.splitn(2, "->");
+        let qubit = resolve_operand(parts.next().unwrap_or("").trim(), span)?;
+        let target_part = parts
+            .next()
+            .ok_or_else(|| ParseError::new("expected '->' in measure statement", span))?;
+        let target = resolve_operand(target_part.trim(), span)?;
+        model.add_inst(Instruction::M { qubit, target });
+        return Ok(());
+    }
+
+    if let Some(rest) = statement.strip_prefix("reset ") {
+        let qubit = resolve_operand(rest.trim(), span)?;
+        model.add_inst(Instruction::Reset(Single::new(qubit)));
+        return Ok(());
+    }
+
+    if let Some(rest) = statement.strip_prefix("cx ") {
+        let (control, target) = parse_pair(rest, span)?;
+        model.add_inst(Instruction::Cx(Controlled::new(control, target)));
+        return Ok(());
+    }
+
+    if let Some(rest) = statement.strip_prefix("h ") {
+        let qubit = resolve_operand(rest.trim(), span)?;
+        model.add_inst(Instruction::H(Single::new(qubit)));
+        return Ok(());
+    }
+
+    if let Some(rest) = statement.strip_prefix("x ") {
+        let qubit = resolve_operand(rest.trim(), span)?;
+        model.add_inst(Instruction::X(Single::new(qubit)));
+        return Ok(());
+    }
+
+    if let Some(rest) = statement.strip_prefix("rx") {
+        let (theta, qubit) = parse_rotation_operands(rest, span)?;
+        model.add_inst(Instruction::Rx(Rotated::new(qubit, theta)));
+        return Ok(());
+    }
+
+    if let Some(rest) = statement.strip_prefix("ry") {
+        let (theta, qubit) = parse_rotation_operands(rest, span)?;
+        model.add_inst(Instruction::Ry(Rotated::new(qubit, theta)));
+        return Ok(());
+    }
+
+    if let Some(rest) = statement.strip_prefix("rz") {
+        let (theta, qubit) = parse_rotation_operands(rest, span)?;
+        model.add_inst(Instruction::Rz(Rotated::new(qubit, theta)));
+        return Ok(());
+    }
+
+    Err(ParseError::new(
+        format!("unsupported statement: {}", statement),
+        span,
+    ))
+}
+
+/// Parses a `name[size]` declaration, e.g. `q[5]`.
+fn parse_declaration(text: &str, span: Span) -> Result<(String, u64), ParseError> {
+    let text = text.trim();
+    let open = text
+        .find('[')
+        .ok_or_else(|| ParseError::new(format!("expected '[' in declaration: {}", text), span))?;
+    let close = text
+        .find(']')
+        .ok_or_else(|| ParseError::new(format!("expected ']' in declaration: {}", text), span))?;
+
+    let name = text[..open].trim().to_string();
+    let size = text[open + 1..close]
+        .trim()
+        .parse::<u64>()
+        .map_err(|_| ParseError::new(format!("invalid register size: {}", text), span))?;
+    Ok((name, size))
+}
+
+/// Resolves an indexed operand like `q[2]` to the flat name the emitter
+/// expects (`q2`).
+fn resolve_operand(text: &str, span: Span) -> Result<String, ParseError> {
+    let open = text
+        .find('[')
+        .ok_or_else(|| ParseError::new(format!("expected indexed operand: {}", text), span))?;
+    let close = text
+        .find(']')
+        .ok_or_else(|| ParseError::new(format!("expected indexed operand: {}", text), span))?;
+
+    let name = text[..open].trim();
+    let index = text[open + 1..close].trim();
+    Ok(format!("{}{}", name, index))
+}
+
+fn parse_pair(text: &str, span: Span) -> Result<(String, String), ParseError> {
+    let mut operands = text.splitn(2, ',');
+    let first = resolve_operand(
+        operands
+            .next()
+            .ok_or_else(|| ParseError::new("expected two operands", span))?
+            .trim(),
+        span,
+    )?;
+    let second = resolve_operand(
+        operands
+            .next()
+            .ok_or_else(|| ParseError::new("expected two operands", span))?
+            .trim(),
+        span,
+    )?;
+    Ok((first, second))
+}
+
+/// Parses the `(theta) q[i]` tail of a rotation gate.
+fn parse_rotation_operands(text: &str, span: Span) -> Result<(f64, String), ParseError> {
+    let open = text
+        .find('(')
+        .ok_or_else(|| ParseError::new("expected '(' after rotation gate name", span))?;
+    let close = text
+        .find(')')
+        .ok_or_else(|| ParseError::new("expected ')' after rotation angle", span))?;
+
+    let theta = text[open + 1..close]
+        .trim()
+        .parse::<f64>()
+        .map_err(|_| ParseError::new(format!("invalid rotation angle: {}", text), span))?;
+    let qubit = resolve_operand(text[close + 1..].trim(), span)?;
+    Ok((theta, qubit))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_bell_circuit() {
+        let source = r#"
+            OPENQASM 2.0;
+            include "qelib1.inc";
+            qreg q[2];
+            creg c[2];
+            h q[0];
+            cx q[0],q[1];
+            measure q[0] -> c[0];
+            measure q[1] -> c[1];
+        "#;
+
+        let model = SemanticModel::from_qasm(source).unwrap();
+        assert_eq!(model.qubits.len(), 2);
+        assert_eq!(model.registers.len(), 1);
+        assert_eq!(model.registers[0].size, 2);
+        assert_eq!(model.instructions.len(), 4);
+    }
+
+    #[test]
+    fn reports_span_on_unsupported_statement() {
+        let err = SemanticModel::from_qasm("qreg q[1];\nbarrier q[0];").unwrap_err();
+        assert!(err.message.contains("barrier"));
+        // The span covers the second `;`-terminated statement, "\nbarrier q[0]".
+        assert_eq!(err.span, Span { start: 10, end: 23 });
+    }
+}
@@ -0,0 +1,324 @@
+use super::CodecError;
+use crate::interop::{
+    ClassicalRegister, Controlled, Instruction, QuantumRegister, Rotated, SemanticModel, Single,
+};
+
+const TAG_X: u8 = 0;
+const TAG_Y: u8 = 1;
+const TAG_Z: u8 = 2;
+const TAG_H: u8 = 3;
+const TAG_S: u8 = 4;
+const TAG_SDG: u8 = 5;
+const TAG_T: u8 = 6;
+const TAG_TDG: u8 = 7;
+const TAG_RX: u8 = 8;
+const TAG_RY: u8 = 9;
+const TAG_RZ: u8 = 10;
+const TAG_CX: u8 = 11;
+const TAG_SWAP: u8 = 12;
+const TAG_M: u8 = 13;
+const TAG_RESET: u8 = 14;
+const TAG_ADJOINT: u8 = 15;
+const TAG_CONTROLLED: u8 = 16;
+
+/// Encodes `model` as a compact binary form: a varint-prefixed string for the
+/// model name, a varint-prefixed list of qubit registers, a varint-prefixed
+/// list of classical registers, then a
+/// varint-prefixed list of instructions, each a tag byte followed by its
+/// operand fields.
+pub fn encode(model: &SemanticModel) -> Vec<u8> {
+    let mut buf = vec![];
+    write_string(&mut buf, &model.name);
+
+    write_varint(&mut buf, model.qubits.len() as u64);
+    for qubit in &model.qubits {
+        write_string(&mut buf, &qubit.name);
+        write_varint(&mut buf, qubit.index);
+    }
+
+    write_varint(&mut buf, model.registers.len() as u64);
+    for register in &model.registers {
+        write_string(&mut buf, &register.name);
+        write_varint(&mut buf, register.size);
+    }
+
+    write_varint(&mut buf, model.instructions.len() as u64);
+    for inst in &model.instructions {
+        write_instruction(&mut buf, inst);
+    }
+
+    buf
+}
+
+pub fn decode(bytes: &[u8]) -> Result<SemanticModel, CodecError> {
+    let mut cursor = Cursor { bytes, position: 0 };
+
+    let name = cursor.read_string()?;
+    let mut model = SemanticModel::new(name);
+
+    let qubit_count = cursor.read_varint()?;
+    for _ in 0..qubit_count {
+        let name = cursor.read_string()?;
+        let index = cursor.read_varint()?;
+        model.add_reg(QuantumRegister::new(name, index).as_register());
+    }
+
+    let register_count = cursor.read_varint()?;
+    for _ in 0..register_count {
+        let name = cursor.read_string()?;
+        let size = cursor.read_varint()?;
+        model.add_reg(ClassicalRegister::new(name, size).as_register());
+    }
+
+    let instruction_count = cursor.read_varint()?;
+    for _ in 0..instruction_count {
+        model.add_inst(cursor.read_instruction()?);
+    }
+
+    Ok(model)
+}
+
+fn write_instruction(buf: &mut Vec<u8>, inst: &Instruction) {
+    match inst {
+        Instruction::X(s) => {
+            buf.push(TAG_X);
+            write_string(buf, &s.qubit);
+        }
+        Instruction::Y(s) => {
+            buf.push(TAG_Y);
+            write_string(buf, &s.qubit);
+        }
+        Instruction::Z(s) => {
+            buf.push(TAG_Z);
+            write_string(buf, &s.qubit);
+        }
+        Instruction::H(s) => {
+            buf.push(TAG_H);
+            write_string(buf, &s.qubit);
+        }
+        Instruction::S(s) => {
+            buf.push(TAG_S);
+            write_string(buf, &s.qubit);
+        }
+        Instruction::Sdg(s) => {
+            buf.push(TAG_SDG);
+            write_string(buf, &s.qubit);
+        }
+        Instruction::T(s) => {
+            buf.push(TAG_T);
+            write_string(buf, &s.qubit);
+        }
+        Instruction::Tdg(s) => {
+            buf.push(TAG_TDG);
+            write_string(buf, &s.qubit);
+        }
+        Instruction::Rx(r) => {
+            buf.push(TAG_RX);
+            write_string(buf, &r.qubit);
+            write_f64(buf, r.theta);
+        }
+        Instruction::Ry(r) => {
+            buf.push(TAG_RY);
+            write_string(buf, &r.qubit);
+            write_f64(buf, r.theta);
+        }
+        Instruction::Rz(r) => {
+            buf.push(TAG_RZ);
+            write_string(buf, &r.qubit);
+            write_f64(buf, r.theta);
+        }
+        Instruction::Cx(c) => {
+            buf.push(TAG_CX);
+            write_string(buf, &c.control);
+            write_string(buf, &c.target);
+        }
+        Instruction::Swap(c) => {
+            buf.push(TAG_SWAP);
+            write_string(buf, &c.control);
+            write_string(buf, &c.target);
+        }
+        Instruction::M { qubit, target } => {
+            buf.push(TAG_M);
+            write_string(buf, qubit);
+            write_string(buf, target);
+        }
+        Instruction::Reset(s) => {
+            buf.push(TAG_RESET);
+            write_string(buf, &s.qubit);
+        }
+        Instruction::Adjoint(inner) => {
+            buf.push(TAG_ADJOINT);
+            write_instruction(buf, inner);
+        }
+        Instruction::Controlled(controls, inner) => {
+            buf.push(TAG_CONTROLLED);
+            write_varint(buf, controls.len() as u64);
+            for control in controls {
+                write_string(buf, control);
+            }
+            write_instruction(buf, inner);
+        }
+    }
+}
+
+fn write_varint(buf: &mut Vec<u8>, mut value: u64) {
+    loop {
+        let byte = (value & 0x7f) as u8;
+        value >>= 7;
+        if value == 0 {
+            buf.push(byte);
+            break;
+        }
+        buf.push(byte | 0x80);
+    }
+}
+
+fn write_string(buf: &mut Vec<u8>, value: &str) {
+    write_varint(buf, value.len() as u64);
+    buf.extend_from_slice(value.as_bytes());
+}
+
+fn write_f64(buf: &mut Vec<u8>, value: f64) {
+    buf.extend_from_slice(&value.to_le_bytes());
+}
+
+struct Cursor<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Cursor<'a> {
+    fn read_varint(&mut self) -> Result<u64, CodecError> {
+        let mut value = 0u64;
+        let mut shift = 0;
+        loop {
+            let byte = *self
+                .bytes
+                .get(self.position)
+                .ok_or_else(|| CodecError::new("unexpected end of input while reading a varint"))?;
+            self.position += 1;
+            value |= ((byte & 0x7f) as u64) << shift;
+            if byte & 0x80 == 0 {
+                break;
+            }
+            shift += 7;
+        }
+        Ok(value)
+    }
+
+    fn read_string(&mut self) -> Result<String, CodecError> {
+        let len = self.read_varint()? as usize;
+        let end = self.position + len;
+        let slice = self
+            .bytes
+            .get(self.position..end)
+            .ok_or_else(|| CodecError::new("unexpected end of input while reading a string"))?;
+        self.position = end;
+        String::from_utf8(slice.to_vec())
+            .map_err(|_| CodecError::new("string field was not valid utf-8"))
+    }
+
+    fn read_f64(&mut self) -> Result<f64, CodecError> {
+        let end = self.position + 8;
+        let slice = self
+            .bytes
+            .get(self.position..end)
+            .ok_or_else(|| CodecError::new("unexpected end of input while reading a double"))?;
+        self.position = end;
+        let mut array = [0u8; 8];
+        array.copy_from_slice(slice);
+        Ok(f64::from_le_bytes(array))
+    }
+
+    fn read_instruction(&mut self) -> Result<Instruction, CodecError> {
+        let tag = *self
+            .bytes
+            .get(self.position)
+            .ok_or_else(|| CodecError::new("unexpected end of input while reading an instruction tag"))?;
+        self.position += 1;
+
+        match tag {
+            TAG_X => Ok(Instruction::X(Single::new(self.read_string()?))),
+            TAG_Y => Ok(Instruction::Y(Single::new(self.read_string()?))),
+            TAG_Z => Ok(Instruction::Z(Single::new(self.read_string()?))),
+            TAG_H => Ok(Instruction::H(Single::new(self.read_string()?))),
+            TAG_S => Ok(Instruction::S(Single::new(self.read_string()?))),
+            TAG_SDG => Ok(Instruction::Sdg(Single::new(self.read_string()?))),
+            TAG_T => Ok(Instruction::T(Single::new(self.read_string()?))),
+            TAG_TDG => Ok(Instruction::Tdg(Single::new(self.read_string()?))),
+            TAG_RX => {
+                let qubit = self.read_string()?;
+                let theta = self.read_f64()?;
+                Ok(Instruction::Rx(Rotated::new(qubit, theta)))
+            }
+            TAG_RY => {
+                let qubit = self.read_string()?;
+                let theta = self.read_f64()?;
+                Ok(Instruction::Ry(Rotated::new(qubit, theta)))
+            }
+            TAG_RZ => {
+                let qubit = self.read_string()?;
+                let theta = self.read_f64()?;
+                Ok(Instruction::Rz(Rotated::new(qubit, theta)))
+            }
+            TAG_CX => {
+                let control = self.read_string()?;
+                let target = self.read_string()?;
+                Ok(Instruction::Cx(Controlled::new(control, target)))
+            }
+            TAG_SWAP => {
+                let a = self.read_string()?;
+                let b = self.read_string()?;
+                Ok(Instruction::Swap(Controlled::new(a, b)))
+            }
+            TAG_M => {
+                let qubit = self.read_string()?;
+                let target = self.read_string()?;
+                Ok(Instruction::M { qubit, target })
+            }
+            TAG_RESET => Ok(Instruction::Reset(Single::new(self.read_string()?))),
+            TAG_ADJOINT => Ok(Instruction::Adjoint(Box::new(self.read_instruction()?))),
+            TAG_CONTROLLED => {
+                let count = self.read_varint()? as usize;
+                let mut controls = Vec::with_capacity(count);
+                for _ in 0..count {
+                    controls.push(self.read_string()?);
+                }
+                let inner = self.read_instruction()?;
+                Ok(Instruction::Controlled(controls, Box::new(inner)))
+            }
+            other => Err(CodecError::new(format!("unrecognized instruction tag {}", other))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interop::{ClassicalRegister, Controlled, QuantumRegister, Single};
+
+    #[test]
+    fn round_trips_bell_circuit() {
+        let mut model = SemanticModel::new(String::from("Bell circuit"));
+        model.add_reg(QuantumRegister::new(String::from("qr"), 0).as_register());
+        model.add_reg(QuantumRegister::new(String::from("qr"), 1).as_register());
+        model.add_reg(ClassicalRegister::new(String::from("qc"), 2).as_register());
+        model.add_inst(Instruction::H(Single::new(String::from("qr0"))));
+        model.add_inst(Instruction::Cx(Controlled::new(
+            String::from("qr0"),
+            String::from("qr1"),
+        )));
+        model.add_inst(Instruction::Rx(Rotated::new(
+            String::from("qr0"),
+            std::f64::consts::PI,
+        )));
+        model.add_inst(Instruction::Controlled(
+            vec![String::from("qr0"), String::from("qr1")],
+            Box::new(Instruction::X(Single::new(String::from("qr2")))),
+        ));
+
+        let encoded = encode(&model);
+        let decoded = decode(&encoded).unwrap();
+        assert_eq!(model, decoded);
+    }
+}
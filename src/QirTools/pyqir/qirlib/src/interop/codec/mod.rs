@@ -0,0 +1,19 @@
+//! Round-trip serialization for `SemanticModel`, in both a human-readable
+//! text form (`text`) and a compact length-prefixed binary form (`binary`).
+//! Both guarantee `decode(encode(m)) == m`.
+
+pub mod binary;
+pub mod text;
+
+#[derive(Debug, PartialEq)]
+pub struct CodecError {
+    pub message: String,
+}
+
+impl CodecError {
+    pub(crate) fn new(message: impl Into<String>) -> Self {
+        CodecError {
+            message: message.into(),
+        }
+    }
+}
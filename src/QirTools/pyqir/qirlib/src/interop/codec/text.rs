@@ -0,0 +1,332 @@
+use super::CodecError;
+use crate::interop::{
+    ClassicalRegister, Controlled, Instruction, QuantumRegister, Rotated, SemanticModel, Single,
+};
+
+/// Encodes `model` as a deterministic, ordered text form: one `MODEL` line,
+/// one `QUBIT`/`CREG` line per register in declaration order, and one `INST`
+/// line per instruction in program order.
+///
+/// Every name field (model name, register names, qubit operands) is escaped
+/// so that a name containing whitespace or a newline round-trips: the format
+/// is otherwise whitespace/line delimited with no quoting, so an unescaped
+/// name could merge into or split across fields.
+pub fn encode(model: &SemanticModel) -> String {
+    let mut lines = vec![format!("MODEL {}", escape(&model.name))];
+    for qubit in &model.qubits {
+        lines.push(format!("QUBIT {} {}", escape(&qubit.name), qubit.index));
+    }
+    for register in &model.registers {
+        lines.push(format!("CREG {} {}", escape(&register.name), register.size));
+    }
+    for inst in &model.instructions {
+        let mut tokens = vec![];
+        write_instruction(inst, &mut tokens);
+        lines.push(format!("INST {}", tokens.join(" ")));
+    }
+    lines.join("\n")
+}
+
+/// Escapes backslash and every whitespace character (space, newline,
+/// carriage-return and tab have short forms, any other is written as
+/// `\u<hex>;`), and writes an empty name as `\e`, so an arbitrary name can be
+/// written as a single non-empty whitespace-delimited field.
+fn escape(name: &str) -> String {
+    if name.is_empty() {
+        return String::from("\\e");
+    }
+    let mut out = String::with_capacity(name.len());
+    for c in name.chars() {
+        match c {
+            '\\' => out.push_str("\\\\"),
+            ' ' => out.push_str("\\s"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if c.is_whitespace() => out.push_str(&format!("\\u{:x};", c as u32)),
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+/// Reverses `escape`.
+fn unescape(field: &str, line_number: usize) -> Result<String, CodecError> {
+    let mut out = String::with_capacity(field.len());
+    let mut chars = field.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('\\') => out.push('\\'),
+            Some('s') => out.push(' '),
+            Some('n') => out.push('\n'),
+            Some('r') => out.push('\r'),
+            Some('t') => out.push('\t'),
+            Some('e') => {}
+            Some('u') => {
+                let hex: String = chars.by_ref().take_while(|&h| h != ';').collect();
+                match u32::from_str_radix(&hex, 16).ok().and_then(char::from_u32) {
+                    Some(decoded) => out.push(decoded),
+                    None => {
+                        return Err(CodecError::new(format!(
+                            "invalid escape '\\u{}' on line {}",
+                            hex, line_number
+                        )))
+                    }
+                }
+            }
+            Some(other) => {
+                return Err(CodecError::new(format!(
+                    "invalid escape '\\{}' on line {}",
+                    other, line_number
+                )))
+            }
+            None => {
+                return Err(CodecError::new(format!(
+                    "dangling escape at end of field on line {}",
+                    line_number
+                )))
+            }
+        }
+    }
+    Ok(out)
+}
+
+fn next_name<'a>(
+    words: &mut impl Iterator<Item = &'a str>,
+    line_number: usize,
+) -> Result<String, CodecError> {
+    unescape(next_word(words, line_number)?, line_number)
+}
+
+pub fn decode(text: &str) -> Result<SemanticModel, CodecError> {
+    let mut model = SemanticModel::new(String::from(""));
+    let mut named = false;
+
+    for (line_number, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        let mut words = line.split_whitespace();
+        let tag = words
+            .next()
+            .ok_or_else(|| CodecError::new(format!("empty line {}", line_number)))?;
+
+        match tag {
+            "MODEL" => {
+                model.name = match words.next() {
+                    Some(word) => unescape(word, line_number)?,
+                    None => String::new(),
+                };
+                named = true;
+            }
+            "QUBIT" => {
+                let name = next_name(&mut words, line_number)?;
+                let index = next_u64(&mut words, line_number)?;
+                model.add_reg(QuantumRegister::new(name, index).as_register());
+            }
+            "CREG" => {
+                let name = next_name(&mut words, line_number)?;
+                let size = next_u64(&mut words, line_number)?;
+                model.add_reg(ClassicalRegister::new(name, size).as_register());
+            }
+            "INST" => {
+                let inst = read_instruction(&mut words, line_number)?;
+                model.add_inst(inst);
+            }
+            other => {
+                return Err(CodecError::new(format!(
+                    "unrecognized tag '{}' on line {}",
+                    other, line_number
+                )))
+            }
+        }
+    }
+
+    if !named {
+        return Err(CodecError::new("missing MODEL line"));
+    }
+    Ok(model)
+}
+
+fn next_word<'a>(
+    words: &mut impl Iterator<Item = &'a str>,
+    line_number: usize,
+) -> Result<&'a str, CodecError> {
+    words
+        .next()
+        .ok_or_else(|| CodecError::new(format!("missing field on line {}", line_number)))
+}
+
+fn next_u64<'a>(
+    words: &mut impl Iterator<Item = &'a str>,
+    line_number: usize,
+) -> Result<u64, CodecError> {
+    next_word(words, line_number)?
+        .parse::<u64>()
+        .map_err(|_| CodecError::new(format!("expected integer on line {}", line_number)))
+}
+
+fn next_f64<'a>(
+    words: &mut impl Iterator<Item = &'a str>,
+    line_number: usize,
+) -> Result<f64, CodecError> {
+    next_word(words, line_number)?
+        .parse::<f64>()
+        .map_err(|_| CodecError::new(format!("expected float on line {}", line_number)))
+}
+
+fn write_instruction(inst: &Instruction, out: &mut Vec<String>) {
+    match inst {
+        Instruction::X(s) => out.extend([String::from("X"), escape(&s.qubit)]),
+        Instruction::Y(s) => out.extend([String::from("Y"), escape(&s.qubit)]),
+        Instruction::Z(s) => out.extend([String::from("Z"), escape(&s.qubit)]),
+        Instruction::H(s) => out.extend([String::from("H"), escape(&s.qubit)]),
+        Instruction::S(s) => out.extend([String::from("S"), escape(&s.qubit)]),
+        Instruction::Sdg(s) => out.extend([String::from("SDG"), escape(&s.qubit)]),
+        Instruction::T(s) => out.extend([String::from("T"), escape(&s.qubit)]),
+        Instruction::Tdg(s) => out.extend([String::from("TDG"), escape(&s.qubit)]),
+        Instruction::Rx(r) => out.extend([String::from("RX"), escape(&r.qubit), r.theta.to_string()]),
+        Instruction::Ry(r) => out.extend([String::from("RY"), escape(&r.qubit), r.theta.to_string()]),
+        Instruction::Rz(r) => out.extend([String::from("RZ"), escape(&r.qubit), r.theta.to_string()]),
+        Instruction::Cx(c) => {
+            out.extend([String::from("CX"), escape(&c.control), escape(&c.target)])
+        }
+        Instruction::Swap(c) => {
+            out.extend([String::from("SWAP"), escape(&c.control), escape(&c.target)])
+        }
+        Instruction::M { qubit, target } => {
+            out.extend([String::from("M"), escape(qubit), escape(target)])
+        }
+        Instruction::Reset(s) => out.extend([String::from("RESET"), escape(&s.qubit)]),
+        Instruction::Adjoint(inner) => {
+            out.push(String::from("ADJOINT"));
+            write_instruction(inner, out);
+        }
+        Instruction::Controlled(controls, inner) => {
+            out.push(String::from("CONTROLLED"));
+            out.push(controls.len().to_string());
+            out.extend(controls.iter().map(|c| escape(c)));
+            write_instruction(inner, out);
+        }
+    }
+}
+
+fn read_instruction<'a>(
+    tokens: &mut impl Iterator<Item = &'a str>,
+    line_number: usize,
+) -> Result<Instruction, CodecError> {
+    let opcode = next_word(tokens, line_number)?;
+    match opcode {
+        "X" => Ok(Instruction::X(Single::new(next_name(tokens, line_number)?))),
+        "Y" => Ok(Instruction::Y(Single::new(next_name(tokens, line_number)?))),
+        "Z" => Ok(Instruction::Z(Single::new(next_name(tokens, line_number)?))),
+        "H" => Ok(Instruction::H(Single::new(next_name(tokens, line_number)?))),
+        "S" => Ok(Instruction::S(Single::new(next_name(tokens, line_number)?))),
+        "SDG" => Ok(Instruction::Sdg(Single::new(next_name(tokens, line_number)?))),
+        "T" => Ok(Instruction::T(Single::new(next_name(tokens, line_number)?))),
+        "TDG" => Ok(Instruction::Tdg(Single::new(next_name(tokens, line_number)?))),
+        "RX" => {
+            let qubit = next_name(tokens, line_number)?;
+            let theta = next_f64(tokens, line_number)?;
+            Ok(Instruction::Rx(Rotated::new(qubit, theta)))
+        }
+        "RY" => {
+            let qubit = next_name(tokens, line_number)?;
+            let theta = next_f64(tokens, line_number)?;
+            Ok(Instruction::Ry(Rotated::new(qubit, theta)))
+        }
+        "RZ" => {
+            let qubit = next_name(tokens, line_number)?;
+            let theta = next_f64(tokens, line_number)?;
+            Ok(Instruction::Rz(Rotated::new(qubit, theta)))
+        }
+        "CX" => {
+            let control = next_name(tokens, line_number)?;
+            let target = next_name(tokens, line_number)?;
+            Ok(Instruction::Cx(Controlled::new(control, target)))
+        }
+        "SWAP" => {
+            let a = next_name(tokens, line_number)?;
+            let b = next_name(tokens, line_number)?;
+            Ok(Instruction::Swap(Controlled::new(a, b)))
+        }
+        "M" => {
+            let qubit = next_name(tokens, line_number)?;
+            let target = next_name(tokens, line_number)?;
+            Ok(Instruction::M { qubit, target })
+        }
+        "RESET" => Ok(Instruction::Reset(Single::new(next_name(
+            tokens,
+            line_number,
+        )?))),
+        "ADJOINT" => {
+            let inner = read_instruction(tokens, line_number)?;
+            Ok(Instruction::Adjoint(Box::new(inner)))
+        }
+        "CONTROLLED" => {
+            let count = next_u64(tokens, line_number)? as usize;
+            let mut controls = Vec::with_capacity(count);
+            for _ in 0..count {
+                controls.push(next_name(tokens, line_number)?);
+            }
+            let inner = read_instruction(tokens, line_number)?;
+            Ok(Instruction::Controlled(controls, Box::new(inner)))
+        }
+        other => Err(CodecError::new(format!(
+            "unrecognized opcode '{}' on line {}",
+            other, line_number
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interop::{ClassicalRegister, Controlled, QuantumRegister, Single};
+
+    #[test]
+    fn round_trips_bell_circuit() {
+        let mut model = SemanticModel::new(String::from("Bell circuit"));
+        model.add_reg(QuantumRegister::new(String::from("qr"), 0).as_register());
+        model.add_reg(QuantumRegister::new(String::from("qr"), 1).as_register());
+        model.add_reg(ClassicalRegister::new(String::from("qc"), 2).as_register());
+        model.add_inst(Instruction::H(Single::new(String::from("qr0"))));
+        model.add_inst(Instruction::Cx(Controlled::new(
+            String::from("qr0"),
+            String::from("qr1"),
+        )));
+        model.add_inst(Instruction::Adjoint(Box::new(Instruction::S(Single::new(
+            String::from("qr0"),
+        )))));
+        model.add_inst(Instruction::Controlled(
+            vec![String::from("qr0"), String::from("qr1")],
+            Box::new(Instruction::X(Single::new(String::from("qr2")))),
+        ));
+
+        let encoded = encode(&model);
+        let decoded = decode(&encoded).unwrap();
+        assert_eq!(model, decoded);
+    }
+
+    #[test]
+    fn round_trips_names_with_whitespace_and_newlines() {
+        let mut model = SemanticModel::new(String::from("a\nb c"));
+        model.add_reg(QuantumRegister::new(String::from("weird qubit"), 0).as_register());
+        model.add_reg(ClassicalRegister::new(String::from("c reg\twith\ttabs"), 1).as_register());
+        model.add_inst(Instruction::H(Single::new(String::from("weird qubit0"))));
+        model.add_inst(Instruction::M {
+            qubit: String::from("weird qubit0"),
+            target: String::from("c reg\twith\ttabs0"),
+        });
+
+        let encoded = encode(&model);
+        let decoded = decode(&encoded).unwrap();
+        assert_eq!(model, decoded);
+    }
+}
@@ -0,0 +1,216 @@
+pub mod codec;
+pub mod optimize;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct QuantumRegister {
+    pub name: String,
+    pub index: u64,
+}
+
+impl QuantumRegister {
+    pub fn new(name: String, index: u64) -> Self {
+        QuantumRegister { name, index }
+    }
+
+    pub fn as_register(&self) -> Register {
+        Register::Quantum(self.clone())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ClassicalRegister {
+    pub name: String,
+    pub size: u64,
+}
+
+impl ClassicalRegister {
+    pub fn new(name: String, size: u64) -> Self {
+        ClassicalRegister { name, size }
+    }
+
+    pub fn as_register(&self) -> Register {
+        Register::Classical(self.clone())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Register {
+    Quantum(QuantumRegister),
+    Classical(ClassicalRegister),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Single {
+    pub qubit: String,
+}
+
+impl Single {
+    pub fn new(qubit: String) -> Self {
+        Single { qubit }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Controlled {
+    pub control: String,
+    pub target: String,
+}
+
+impl Controlled {
+    pub fn new(control: String, target: String) -> Self {
+        Controlled { control, target }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Rotated {
+    pub qubit: String,
+    pub theta: f64,
+}
+
+impl Rotated {
+    pub fn new(qubit: String, theta: f64) -> Self {
+        Rotated { qubit, theta }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Instruction {
+    X(Single),
+    Y(Single),
+    Z(Single),
+    H(Single),
+    S(Single),
+    Sdg(Single),
+    T(Single),
+    Tdg(Single),
+    Rx(Rotated),
+    Ry(Rotated),
+    Rz(Rotated),
+    Cx(Controlled),
+    Swap(Controlled),
+    M { qubit: String, target: String },
+    Reset(Single),
+    /// Applies the adjoint specialization of the wrapped instruction.
+    Adjoint(Box<Instruction>),
+    /// Applies the wrapped instruction controlled on every qubit in the list.
+    Controlled(Vec<String>, Box<Instruction>),
+}
+
+impl Instruction {
+    /// Returns the adjoint specialization of this instruction, recursing
+    /// through `Adjoint`/`Controlled` wrappers so functor composition (e.g.
+    /// `Adjoint(Controlled(ctrls, S))`) resolves to the right gate instead of
+    /// being treated as self-adjoint. Shared by every backend (emitter,
+    /// simulator, ...) so the functor semantics can't drift between them.
+    pub fn adjoint(&self) -> Instruction {
+        match self {
+            Instruction::S(single) => Instruction::Sdg(single.clone()),
+            Instruction::Sdg(single) => Instruction::S(single.clone()),
+            Instruction::T(single) => Instruction::Tdg(single.clone()),
+            Instruction::Tdg(single) => Instruction::T(single.clone()),
+            Instruction::Rx(rotated) => {
+                Instruction::Rx(Rotated::new(rotated.qubit.clone(), -rotated.theta))
+            }
+            Instruction::Ry(rotated) => {
+                Instruction::Ry(Rotated::new(rotated.qubit.clone(), -rotated.theta))
+            }
+            Instruction::Rz(rotated) => {
+                Instruction::Rz(Rotated::new(rotated.qubit.clone(), -rotated.theta))
+            }
+            Instruction::Adjoint(doubly_inner) => (**doubly_inner).clone(),
+            Instruction::Controlled(controls, doubly_inner) => {
+                Instruction::Controlled(controls.clone(), Box::new(doubly_inner.adjoint()))
+            }
+            self_adjoint => self_adjoint.clone(),
+        }
+    }
+
+    /// A human-readable name for this instruction's variant, used in panic
+    /// messages and diagnostics across backends.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Instruction::X(_) => "X",
+            Instruction::Y(_) => "Y",
+            Instruction::Z(_) => "Z",
+            Instruction::H(_) => "H",
+            Instruction::S(_) => "S",
+            Instruction::Sdg(_) => "Sdg",
+            Instruction::T(_) => "T",
+            Instruction::Tdg(_) => "Tdg",
+            Instruction::Rx(_) => "Rx",
+            Instruction::Ry(_) => "Ry",
+            Instruction::Rz(_) => "Rz",
+            Instruction::Cx(_) => "Cx",
+            Instruction::Swap(_) => "Swap",
+            Instruction::M { .. } => "M",
+            Instruction::Reset(_) => "Reset",
+            Instruction::Adjoint(_) => "Adjoint",
+            Instruction::Controlled(_, _) => "Controlled",
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct SemanticModel {
+    pub name: String,
+    pub qubits: Vec<QuantumRegister>,
+    pub registers: Vec<ClassicalRegister>,
+    pub instructions: Vec<Instruction>,
+}
+
+impl SemanticModel {
+    pub fn new(name: String) -> Self {
+        SemanticModel {
+            name,
+            qubits: vec![],
+            registers: vec![],
+            instructions: vec![],
+        }
+    }
+
+    pub fn add_reg(&mut self, reg: Register) {
+        match reg {
+            Register::Quantum(q) => self.qubits.push(q),
+            Register::Classical(c) => self.registers.push(c),
+        }
+    }
+
+    pub fn add_inst(&mut self, inst: Instruction) {
+        self.instructions.push(inst);
+    }
+
+    /// Parses OpenQASM 2.0 source into a `SemanticModel` ready for
+    /// `Emitter::write`.
+    pub fn from_qasm(src: &str) -> Result<SemanticModel, crate::frontend::openqasm::ParseError> {
+        crate::frontend::openqasm::parse(src)
+    }
+
+    /// Encodes this model as the deterministic, ordered text form described in
+    /// `codec`.
+    pub fn to_text(&self) -> String {
+        codec::text::encode(self)
+    }
+
+    /// Decodes a model previously produced by `to_text`.
+    pub fn from_text(text: &str) -> Result<SemanticModel, codec::CodecError> {
+        codec::text::decode(text)
+    }
+
+    /// Encodes this model as the compact, length-prefixed binary form
+    /// described in `codec`.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        codec::binary::encode(self)
+    }
+
+    /// Decodes a model previously produced by `to_bytes`.
+    pub fn from_bytes(bytes: &[u8]) -> Result<SemanticModel, codec::CodecError> {
+        codec::binary::decode(bytes)
+    }
+
+    /// Eliminates `Swap` instructions by relabeling qubits instead of
+    /// emitting swap gates. See `optimize::optimize_swaps`.
+    pub fn optimize_swaps(&self) -> (Vec<Instruction>, std::collections::BTreeMap<String, String>) {
+        optimize::optimize_swaps(self)
+    }
+}
@@ -0,0 +1,170 @@
+use std::collections::BTreeMap;
+
+use super::{Instruction, SemanticModel};
+
+/// Eliminates `Swap` instructions by tracking a logical-to-physical qubit
+/// label permutation instead of emitting swap gates — the label-swap trick
+/// that avoids generating swap network gates.
+///
+/// Walks `model.instructions` maintaining a mapping from each logical qubit
+/// name to its current physical qubit name, initialized to identity. A
+/// `Swap(a, b)` swaps the two mapping entries and is dropped; every other
+/// instruction has its qubit operands rewritten through the current mapping
+/// before being kept. Classical register targets (e.g. a measurement's
+/// target) are untouched, since the permutation only ever applies to qubits.
+///
+/// Returns the rewritten instructions and the final mapping, which the
+/// emitter needs to allocate/free and record results for the correct
+/// physical qubits (since `model.qubits` still lists the original logical
+/// names).
+pub fn optimize_swaps(model: &SemanticModel) -> (Vec<Instruction>, BTreeMap<String, String>) {
+    let mut mapping: BTreeMap<String, String> = model
+        .qubits
+        .iter()
+        .map(|qubit| {
+            let name = format!("{}{}", qubit.name, qubit.index);
+            (name.clone(), name)
+        })
+        .collect();
+
+    let mut rewritten = vec![];
+    for inst in model.instructions.iter() {
+        if let Instruction::Swap(pair) = inst {
+            let physical_control = physical_name(&mapping, &pair.control);
+            let physical_target = physical_name(&mapping, &pair.target);
+            mapping.insert(pair.control.clone(), physical_target);
+            mapping.insert(pair.target.clone(), physical_control);
+            continue;
+        }
+        rewritten.push(remap_qubits(inst, &mapping));
+    }
+
+    (rewritten, mapping)
+}
+
+fn physical_name(mapping: &BTreeMap<String, String>, logical: &str) -> String {
+    mapping
+        .get(logical)
+        .cloned()
+        .unwrap_or_else(|| logical.to_string())
+}
+
+fn remap_qubits(inst: &Instruction, mapping: &BTreeMap<String, String>) -> Instruction {
+    use super::{Controlled, Rotated, Single};
+
+    let remap = |name: &str| physical_name(mapping, name);
+    match inst {
+        Instruction::X(s) => Instruction::X(Single::new(remap(&s.qubit))),
+        Instruction::Y(s) => Instruction::Y(Single::new(remap(&s.qubit))),
+        Instruction::Z(s) => Instruction::Z(Single::new(remap(&s.qubit))),
+        Instruction::H(s) => Instruction::H(Single::new(remap(&s.qubit))),
+        Instruction::S(s) => Instruction::S(Single::new(remap(&s.qubit))),
+        Instruction::Sdg(s) => Instruction::Sdg(Single::new(remap(&s.qubit))),
+        Instruction::T(s) => Instruction::T(Single::new(remap(&s.qubit))),
+        Instruction::Tdg(s) => Instruction::Tdg(Single::new(remap(&s.qubit))),
+        Instruction::Reset(s) => Instruction::Reset(Single::new(remap(&s.qubit))),
+        Instruction::Rx(r) => Instruction::Rx(Rotated::new(remap(&r.qubit), r.theta)),
+        Instruction::Ry(r) => Instruction::Ry(Rotated::new(remap(&r.qubit), r.theta)),
+        Instruction::Rz(r) => Instruction::Rz(Rotated::new(remap(&r.qubit), r.theta)),
+        Instruction::Cx(c) => Instruction::Cx(Controlled::new(remap(&c.control), remap(&c.target))),
+        // Only a bare, top-level `Swap` gets the relabeling optimization (see
+        // the loop in `optimize_swaps`). A `Swap` nested inside `Adjoint`/
+        // `Controlled` is not relabeled: it is kept and just has its operands
+        // remapped like any other instruction. Under `Controlled` (e.g. a
+        // Fredkin/controlled-swap gate) it is a conditional gate rather than
+        // an unconditional relabeling.
+        Instruction::Swap(pair) => {
+            Instruction::Swap(Controlled::new(remap(&pair.control), remap(&pair.target)))
+        }
+        Instruction::M { qubit, target } => Instruction::M {
+            qubit: remap(qubit),
+            target: target.clone(),
+        },
+        Instruction::Adjoint(inner) => Instruction::Adjoint(Box::new(remap_qubits(inner, mapping))),
+        Instruction::Controlled(controls, inner) => Instruction::Controlled(
+            controls.iter().map(|c| remap(c)).collect(),
+            Box::new(remap_qubits(inner, mapping)),
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interop::{ClassicalRegister, Controlled, QuantumRegister, Single};
+
+    #[test]
+    fn swap_is_eliminated_by_relabeling() {
+        let mut model = SemanticModel::new(String::from("Swap circuit"));
+        model.add_reg(QuantumRegister::new(String::from("qr"), 0).as_register());
+        model.add_reg(QuantumRegister::new(String::from("qr"), 1).as_register());
+        model.add_reg(ClassicalRegister::new(String::from("qc"), 1).as_register());
+
+        model.add_inst(Instruction::X(Single::new(String::from("qr0"))));
+        model.add_inst(Instruction::Swap(Controlled::new(
+            String::from("qr0"),
+            String::from("qr1"),
+        )));
+        model.add_inst(Instruction::M {
+            qubit: String::from("qr1"),
+            target: String::from("qc0"),
+        });
+
+        let (instructions, mapping) = optimize_swaps(&model);
+
+        assert!(instructions
+            .iter()
+            .all(|inst| !matches!(inst, Instruction::Swap(_))));
+        assert_eq!(instructions.len(), 2);
+        match &instructions[1] {
+            Instruction::M { qubit, target } => {
+                assert_eq!(qubit, "qr0");
+                assert_eq!(target, "qc0");
+            }
+            other => panic!("expected a measurement, got {:?}", other),
+        }
+        assert_eq!(mapping[&String::from("qr0")], "qr1");
+        assert_eq!(mapping[&String::from("qr1")], "qr0");
+    }
+
+    #[test]
+    fn nested_swap_under_controlled_is_remapped_not_eliminated() {
+        let mut model = SemanticModel::new(String::from("Fredkin circuit"));
+        model.add_reg(QuantumRegister::new(String::from("qr"), 0).as_register());
+        model.add_reg(QuantumRegister::new(String::from("qr"), 1).as_register());
+        model.add_reg(QuantumRegister::new(String::from("qr"), 2).as_register());
+
+        // A bare swap first, to exercise the relabeling mapping...
+        model.add_inst(Instruction::Swap(Controlled::new(
+            String::from("qr0"),
+            String::from("qr1"),
+        )));
+        // ...followed by a Fredkin (controlled-swap), which is a real gate
+        // and must survive, with its operands remapped through the
+        // accumulated permutation.
+        model.add_inst(Instruction::Controlled(
+            vec![String::from("qr2")],
+            Box::new(Instruction::Swap(Controlled::new(
+                String::from("qr0"),
+                String::from("qr1"),
+            ))),
+        ));
+
+        let (instructions, _mapping) = optimize_swaps(&model);
+
+        assert_eq!(instructions.len(), 1);
+        match &instructions[0] {
+            Instruction::Controlled(controls, inner) => {
+                assert_eq!(controls, &vec![String::from("qr2")]);
+                match inner.as_ref() {
+                    Instruction::Swap(pair) => {
+                        assert_eq!(pair.control, "qr1");
+                        assert_eq!(pair.target, "qr0");
+                    }
+                    other => panic!("expected a Swap, got {:?}", other),
+                }
+            }
+            other => panic!("expected a Controlled instruction, got {:?}", other),
+        }
+    }
+}
@@ -0,0 +1,4 @@
+pub mod emit;
+pub mod frontend;
+pub mod interop;
+pub mod simulator;
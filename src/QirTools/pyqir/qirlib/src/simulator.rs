@@ -0,0 +1,411 @@
+use std::collections::BTreeMap;
+
+use num_complex::Complex64;
+use rand::Rng;
+
+use crate::interop::{Instruction, Rotated, SemanticModel, Single};
+
+/// The measurement outcomes recorded into each classical register bit, and
+/// optionally the full amplitude vector the circuit ended in.
+pub struct SimulationResult {
+    pub measurements: BTreeMap<String, bool>,
+    pub amplitudes: Option<Vec<Complex64>>,
+}
+
+type Matrix2 = [[Complex64; 2]; 2];
+
+/// Runs `model` against a dense state-vector simulator instead of emitting
+/// LLVM IR. `with_amplitudes` controls whether the full 2^n amplitude vector
+/// is returned alongside the measurement outcomes.
+pub fn simulate(model: &SemanticModel, with_amplitudes: bool) -> SimulationResult {
+    let mut qubit_names: Vec<String> = model
+        .qubits
+        .iter()
+        .map(|reg| format!("{}{}", reg.name, reg.index))
+        .collect();
+    qubit_names.sort();
+
+    let mut qubit_index = BTreeMap::new();
+    for (index, name) in qubit_names.iter().enumerate() {
+        qubit_index.insert(name.clone(), index);
+    }
+
+    let number_of_qubits = qubit_names.len();
+    let mut state = vec![Complex64::new(0.0, 0.0); 1 << number_of_qubits];
+    state[0] = Complex64::new(1.0, 0.0);
+
+    let mut rng = rand::thread_rng();
+    let mut measurements = BTreeMap::new();
+
+    for inst in model.instructions.iter() {
+        apply_instruction(
+            &mut state,
+            number_of_qubits,
+            &qubit_index,
+            inst,
+            &mut measurements,
+            &mut rng,
+        );
+    }
+
+    SimulationResult {
+        measurements,
+        amplitudes: if with_amplitudes { Some(state) } else { None },
+    }
+}
+
+fn apply_instruction(
+    state: &mut [Complex64],
+    number_of_qubits: usize,
+    qubit_index: &BTreeMap<String, usize>,
+    inst: &Instruction,
+    measurements: &mut BTreeMap<String, bool>,
+    rng: &mut impl Rng,
+) {
+    match inst {
+        Instruction::Cx(controlled) => apply_controlled(
+            state,
+            number_of_qubits,
+            &[qubit_index[&controlled.control]],
+            qubit_index[&controlled.target],
+            x_matrix(),
+        ),
+        Instruction::Swap(pair) => apply_swap(
+            state,
+            number_of_qubits,
+            qubit_index[&pair.control],
+            qubit_index[&pair.target],
+        ),
+        Instruction::M { qubit, target } => {
+            let outcome = measure(state, number_of_qubits, qubit_index[qubit], rng);
+            measurements.insert(target.clone(), outcome);
+        }
+        Instruction::Reset(single) => {
+            let target = qubit_index[&single.qubit];
+            let outcome = measure(state, number_of_qubits, target, rng);
+            if outcome {
+                apply_single(state, number_of_qubits, target, x_matrix());
+            }
+        }
+        Instruction::Adjoint(inner) => apply_instruction(
+            state,
+            number_of_qubits,
+            qubit_index,
+            &inner.adjoint(),
+            measurements,
+            rng,
+        ),
+        Instruction::Controlled(controls, inner) => {
+            // `inner` may itself be `Adjoint`/`Controlled` (e.g. a controlled
+            // adjoint rotation); resolve those wrappers the same way
+            // `emit_multi_controlled` does before looking up a gate matrix.
+            let (combined_controls, resolved) = resolve_controlled(controls, inner);
+            let control_bits: Vec<usize> =
+                combined_controls.iter().map(|c| qubit_index[c]).collect();
+            let (target, matrix) = single_qubit_gate(&resolved);
+            apply_controlled(
+                state,
+                number_of_qubits,
+                &control_bits,
+                qubit_index[&target],
+                matrix,
+            );
+        }
+        single_qubit_inst => {
+            let (target, matrix) = single_qubit_gate(single_qubit_inst);
+            apply_single(state, number_of_qubits, qubit_index[&target], matrix);
+        }
+    }
+}
+
+/// Returns the qubit a single-qubit instruction targets and the unitary it
+/// applies. Panics for instructions with no single-qubit matrix (`Cx`,
+/// `Swap`, `M`, `Reset`, and the functor wrappers, which are handled directly
+/// by `apply_instruction`).
+fn single_qubit_gate(inst: &Instruction) -> (String, Matrix2) {
+    match inst {
+        Instruction::X(Single { qubit }) => (qubit.clone(), x_matrix()),
+        Instruction::Y(Single { qubit }) => (qubit.clone(), y_matrix()),
+        Instruction::Z(Single { qubit }) => (qubit.clone(), z_matrix()),
+        Instruction::H(Single { qubit }) => (qubit.clone(), h_matrix()),
+        Instruction::S(Single { qubit }) => (qubit.clone(), s_matrix()),
+        Instruction::Sdg(Single { qubit }) => (qubit.clone(), sdg_matrix()),
+        Instruction::T(Single { qubit }) => (qubit.clone(), t_matrix()),
+        Instruction::Tdg(Single { qubit }) => (qubit.clone(), tdg_matrix()),
+        Instruction::Rx(Rotated { qubit, theta }) => (qubit.clone(), rx_matrix(*theta)),
+        Instruction::Ry(Rotated { qubit, theta }) => (qubit.clone(), ry_matrix(*theta)),
+        Instruction::Rz(Rotated { qubit, theta }) => (qubit.clone(), rz_matrix(*theta)),
+        other => panic!("instruction has no single-qubit matrix: this simulator cannot lower it directly: {}", other.name()),
+    }
+}
+
+/// Resolves a `Controlled(controls, inner)` pair by flattening nested
+/// `Controlled` wrappers into `controls` and resolving a nested `Adjoint` via
+/// `Instruction::adjoint`, until `inner` is a plain gate `single_qubit_gate`
+/// can look up.
+fn resolve_controlled(controls: &[String], inner: &Instruction) -> (Vec<String>, Instruction) {
+    match inner {
+        Instruction::Adjoint(doubly_inner) => {
+            resolve_controlled(controls, &doubly_inner.adjoint())
+        }
+        Instruction::Controlled(inner_controls, doubly_inner) => {
+            let mut combined_controls = controls.to_vec();
+            combined_controls.extend(inner_controls.iter().cloned());
+            resolve_controlled(&combined_controls, doubly_inner)
+        }
+        resolved => (controls.to_vec(), resolved.clone()),
+    }
+}
+
+fn apply_single(state: &mut [Complex64], number_of_qubits: usize, target: usize, matrix: Matrix2) {
+    apply_controlled(state, number_of_qubits, &[], target, matrix)
+}
+
+fn apply_controlled(
+    state: &mut [Complex64],
+    number_of_qubits: usize,
+    controls: &[usize],
+    target: usize,
+    matrix: Matrix2,
+) {
+    let target_bit = 1usize << target;
+    for basis_state in 0..(1usize << number_of_qubits) {
+        if basis_state & target_bit != 0 {
+            continue;
+        }
+        if controls.iter().any(|c| basis_state & (1usize << c) == 0) {
+            continue;
+        }
+        let partner = basis_state | target_bit;
+        let amp0 = state[basis_state];
+        let amp1 = state[partner];
+        state[basis_state] = matrix[0][0] * amp0 + matrix[0][1] * amp1;
+        state[partner] = matrix[1][0] * amp0 + matrix[1][1] * amp1;
+    }
+}
+
+fn apply_swap(state: &mut [Complex64], number_of_qubits: usize, a: usize, b: usize) {
+    if a == b {
+        return;
+    }
+    let bit_a = 1usize << a;
+    let bit_b = 1usize << b;
+    for basis_state in 0..(1usize << number_of_qubits) {
+        let has_a = basis_state & bit_a != 0;
+        let has_b = basis_state & bit_b != 0;
+        if has_a == has_b {
+            continue;
+        }
+        // Only swap from the (a=0, b=1) side to avoid swapping back.
+        if has_a {
+            continue;
+        }
+        let partner = basis_state ^ bit_a ^ bit_b;
+        state.swap(basis_state, partner);
+    }
+}
+
+fn measure(
+    state: &mut [Complex64],
+    number_of_qubits: usize,
+    target: usize,
+    rng: &mut impl Rng,
+) -> bool {
+    let target_bit = 1usize << target;
+    let probability_one: f64 = (0..(1usize << number_of_qubits))
+        .filter(|basis_state| basis_state & target_bit != 0)
+        .map(|basis_state| state[basis_state].norm_sqr())
+        .sum();
+
+    let outcome = rng.gen::<f64>() < probability_one;
+
+    for basis_state in 0..(1usize << number_of_qubits) {
+        let bit_is_set = basis_state & target_bit != 0;
+        if bit_is_set != outcome {
+            state[basis_state] = Complex64::new(0.0, 0.0);
+        }
+    }
+
+    let norm: f64 = state.iter().map(|amp| amp.norm_sqr()).sum::<f64>().sqrt();
+    if norm > 0.0 {
+        for amp in state.iter_mut() {
+            *amp /= norm;
+        }
+    }
+
+    outcome
+}
+
+fn h_matrix() -> Matrix2 {
+    let c = std::f64::consts::FRAC_1_SQRT_2;
+    [
+        [Complex64::new(c, 0.0), Complex64::new(c, 0.0)],
+        [Complex64::new(c, 0.0), Complex64::new(-c, 0.0)],
+    ]
+}
+
+fn x_matrix() -> Matrix2 {
+    [
+        [Complex64::new(0.0, 0.0), Complex64::new(1.0, 0.0)],
+        [Complex64::new(1.0, 0.0), Complex64::new(0.0, 0.0)],
+    ]
+}
+
+fn y_matrix() -> Matrix2 {
+    [
+        [Complex64::new(0.0, 0.0), Complex64::new(0.0, -1.0)],
+        [Complex64::new(0.0, 1.0), Complex64::new(0.0, 0.0)],
+    ]
+}
+
+fn z_matrix() -> Matrix2 {
+    [
+        [Complex64::new(1.0, 0.0), Complex64::new(0.0, 0.0)],
+        [Complex64::new(0.0, 0.0), Complex64::new(-1.0, 0.0)],
+    ]
+}
+
+fn s_matrix() -> Matrix2 {
+    [
+        [Complex64::new(1.0, 0.0), Complex64::new(0.0, 0.0)],
+        [Complex64::new(0.0, 0.0), Complex64::new(0.0, 1.0)],
+    ]
+}
+
+fn sdg_matrix() -> Matrix2 {
+    [
+        [Complex64::new(1.0, 0.0), Complex64::new(0.0, 0.0)],
+        [Complex64::new(0.0, 0.0), Complex64::new(0.0, -1.0)],
+    ]
+}
+
+fn t_matrix() -> Matrix2 {
+    let phase = Complex64::from_polar(1.0, std::f64::consts::FRAC_PI_4);
+    [
+        [Complex64::new(1.0, 0.0), Complex64::new(0.0, 0.0)],
+        [Complex64::new(0.0, 0.0), phase],
+    ]
+}
+
+fn tdg_matrix() -> Matrix2 {
+    let phase = Complex64::from_polar(1.0, -std::f64::consts::FRAC_PI_4);
+    [
+        [Complex64::new(1.0, 0.0), Complex64::new(0.0, 0.0)],
+        [Complex64::new(0.0, 0.0), phase],
+    ]
+}
+
+fn rx_matrix(theta: f64) -> Matrix2 {
+    let (sin, cos) = (theta / 2.0).sin_cos();
+    [
+        [Complex64::new(cos, 0.0), Complex64::new(0.0, -sin)],
+        [Complex64::new(0.0, -sin), Complex64::new(cos, 0.0)],
+    ]
+}
+
+fn ry_matrix(theta: f64) -> Matrix2 {
+    let (sin, cos) = (theta / 2.0).sin_cos();
+    [
+        [Complex64::new(cos, 0.0), Complex64::new(-sin, 0.0)],
+        [Complex64::new(sin, 0.0), Complex64::new(cos, 0.0)],
+    ]
+}
+
+fn rz_matrix(theta: f64) -> Matrix2 {
+    [
+        [Complex64::from_polar(1.0, -theta / 2.0), Complex64::new(0.0, 0.0)],
+        [Complex64::new(0.0, 0.0), Complex64::from_polar(1.0, theta / 2.0)],
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interop::{ClassicalRegister, Controlled, QuantumRegister};
+
+    fn bell_model() -> SemanticModel {
+        let mut model = SemanticModel::new(String::from("Bell circuit"));
+        model.add_reg(QuantumRegister::new(String::from("qr"), 0).as_register());
+        model.add_reg(QuantumRegister::new(String::from("qr"), 1).as_register());
+        model.add_reg(ClassicalRegister::new(String::from("qc"), 2).as_register());
+
+        model.add_inst(Instruction::H(Single::new(String::from("qr0"))));
+        model.add_inst(Instruction::Cx(Controlled::new(
+            String::from("qr0"),
+            String::from("qr1"),
+        )));
+        model.add_inst(Instruction::M {
+            qubit: String::from("qr0"),
+            target: String::from("qc0"),
+        });
+        model.add_inst(Instruction::M {
+            qubit: String::from("qr1"),
+            target: String::from("qc1"),
+        });
+        model
+    }
+
+    #[test]
+    fn bell_circuit_only_measures_00_or_11() {
+        let mut zeros = 0;
+        let mut ones = 0;
+        let trials = 1000;
+        for _ in 0..trials {
+            let model = bell_model();
+            let result = simulate(&model, false);
+            let qc0 = result.measurements[&String::from("qc0")];
+            let qc1 = result.measurements[&String::from("qc1")];
+            assert_eq!(qc0, qc1, "Bell circuit measured opposite outcomes");
+            if qc0 {
+                ones += 1;
+            } else {
+                zeros += 1;
+            }
+        }
+
+        let p_zero = zeros as f64 / trials as f64;
+        assert!(
+            (p_zero - 0.5).abs() < 0.1,
+            "expected ~0.5 probability of 00, got {}",
+            p_zero
+        );
+        assert!(ones > 0 && zeros > 0);
+    }
+
+    #[test]
+    fn controlled_adjoint_rotation_resolves_to_controlled_inverse_rotation() {
+        let mut model = SemanticModel::new(String::from("controlled adjoint rotation"));
+        model.add_reg(QuantumRegister::new(String::from("qr"), 0).as_register());
+        model.add_reg(QuantumRegister::new(String::from("qr"), 1).as_register());
+        model.add_reg(ClassicalRegister::new(String::from("qc"), 1).as_register());
+
+        // X(q0) so the control is set, then a controlled-adjoint rotation on
+        // q1: Controlled(["qr0"], Adjoint(Rx(0.5))) should behave exactly
+        // like Controlled(["qr0"], Rx(-0.5)).
+        model.add_inst(Instruction::X(Single::new(String::from("qr0"))));
+        model.add_inst(Instruction::Controlled(
+            vec![String::from("qr0")],
+            Box::new(Instruction::Adjoint(Box::new(Instruction::Rx(
+                Rotated::new(String::from("qr1"), 0.5),
+            )))),
+        ));
+
+        let nested = simulate(&model, true).amplitudes.unwrap();
+
+        let mut flattened = SemanticModel::new(String::from("controlled inverse rotation"));
+        flattened.add_reg(QuantumRegister::new(String::from("qr"), 0).as_register());
+        flattened.add_reg(QuantumRegister::new(String::from("qr"), 1).as_register());
+        flattened.add_reg(ClassicalRegister::new(String::from("qc"), 1).as_register());
+        flattened.add_inst(Instruction::X(Single::new(String::from("qr0"))));
+        flattened.add_inst(Instruction::Controlled(
+            vec![String::from("qr0")],
+            Box::new(Instruction::Rx(Rotated::new(String::from("qr1"), -0.5))),
+        ));
+
+        let expected = simulate(&flattened, true).amplitudes.unwrap();
+
+        for (a, b) in nested.iter().zip(expected.iter()) {
+            assert!((a - b).norm() < 1e-9, "{:?} != {:?}", nested, expected);
+        }
+    }
+}